@@ -7,3 +7,46 @@ pub trait ECB {
     fn encrypt(&self, plaintext: &u128) -> u128;
     fn decrypt(&self, ciphertext: &u128) -> u128;
 }
+
+/// A trait for the XEX-based tweaked-codebook mode with ciphertext stealing
+/// (XTS), the mode used for disk/sector encryption (dm-crypt, fscrypt).
+///
+/// XTS uses two independent keys: `self` encrypts the data while the `tweak`
+/// cipher derives the per-sector tweak `T = E_tweak(sector_index)`. Each block
+/// `j` of the data unit is enciphered as `C_j = E(P_j ^ T) ^ T`, and the tweak
+/// is advanced between blocks by a GF(2^128) multiply by the primitive element.
+/// Data units whose length is not a block multiple are handled with ciphertext
+/// stealing, so the ciphertext is always the same length as the plaintext. The
+/// data unit must be at least one full block long.
+pub trait XTS {
+    fn encrypt(&self, tweak: &Self, sector_index: u128, data_unit: &mut [u8]);
+    fn decrypt(&self, tweak: &Self, sector_index: u128, data_unit: &mut [u8]);
+}
+
+/// A trait for the Cipher Block Chaining (CBC) ciphermode.
+/// Each block is XORed with the previous ciphertext block before encryption
+/// (`C_i = E(P_i ^ C_{i-1})`), with the chain seeded by a random IV. This gives
+/// the diffusion across blocks that ECB lacks. The input length must be a whole
+/// number of blocks; use a padding layer for arbitrary byte lengths.
+pub trait CBC {
+    fn encrypt(&self, iv: u128, blocks: &mut [u128]);
+    fn decrypt(&self, iv: u128, blocks: &mut [u128]);
+}
+
+/// A trait for the Counter (CTR) ciphermode.
+/// CTR turns the block cipher into a stream cipher by encrypting successive
+/// counter blocks (seeded by a nonce) and XORing the keystream into the data.
+/// Encryption and decryption are the same operation, so it needs no padding and
+/// operates on a byte buffer of any length.
+pub trait CTR {
+    fn apply_keystream(&self, nonce: u128, data: &mut [u8]);
+}
+
+/// A trait for the Cipher Feedback (CFB) ciphermode.
+/// CFB is self-synchronising and, like CTR, acts as a stream cipher
+/// (`C_i = P_i ^ E(C_{i-1})`), seeded by an IV and requiring no padding. It
+/// operates on a byte buffer of any length.
+pub trait CFB {
+    fn encrypt(&self, iv: u128, data: &mut [u8]);
+    fn decrypt(&self, iv: u128, data: &mut [u8]);
+}
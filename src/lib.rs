@@ -2,29 +2,95 @@
 #![forbid(unsafe_code)]
 
 pub mod cipher_modes;
+pub mod streaming;
 
-use crate::cipher_modes::ECB;
+use core::ops::BitXor;
+
+use crate::cipher_modes::{CBC, CFB, CTR, ECB, XTS};
 
 /// This library implements NSA's lightweight block cipher Speck.
 /// The formal specification of Speck can be found: https://eprint.iacr.org/2013/404.pdf
 ///
 /// The Speck parameters are found in Table 4.1 in the above paper.
 
-/// Speck parameters (for 128-bit security)
+/// Speck parameters (ALPHA and BETA are shared by every variant in this crate)
 /// ALPHA and BETA are the parameters to the rotations
-/// ROUNDS is the number of times to apply the round function
+/// ROUNDS is the number of times to apply the round function for Speck128/128
 const ALPHA: u32 = 8;
 const BETA: u32 = 3;
 const ROUNDS: usize = 32;
 
+/// The number of independent blocks the batched API processes per lane. This
+/// mirrors the 128-byte chunks (8 Speck128 blocks) the NEON Speck-XTS code
+/// interleaved to keep the ARX pipeline busy.
+const BLOCK_LANES: usize = 8;
+
+/// The word type a Speck variant operates on.
+///
+/// Speck is defined over a pair of `n`-bit words; the 128-bit block uses
+/// `u64` words while the 64-bit block uses `u32` words. This trait captures
+/// the handful of operations the round function needs so that `round`,
+/// `inv_round`, and `key_schedule` can be written once for the whole family.
+trait Word: Copy + BitXor<Output = Self> {
+    const ZERO: Self;
+
+    fn rotate_left(self, n: u32) -> Self;
+    fn rotate_right(self, n: u32) -> Self;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// The round counter `i`, reduced into this word type for the key schedule.
+    fn from_round(i: usize) -> Self;
+}
+
+impl Word for u32 {
+    const ZERO: Self = 0;
+
+    fn rotate_left(self, n: u32) -> Self {
+        u32::rotate_left(self, n)
+    }
+    fn rotate_right(self, n: u32) -> Self {
+        u32::rotate_right(self, n)
+    }
+    fn wrapping_add(self, other: Self) -> Self {
+        u32::wrapping_add(self, other)
+    }
+    fn wrapping_sub(self, other: Self) -> Self {
+        u32::wrapping_sub(self, other)
+    }
+    fn from_round(i: usize) -> Self {
+        i as u32
+    }
+}
+
+impl Word for u64 {
+    const ZERO: Self = 0;
+
+    fn rotate_left(self, n: u32) -> Self {
+        u64::rotate_left(self, n)
+    }
+    fn rotate_right(self, n: u32) -> Self {
+        u64::rotate_right(self, n)
+    }
+    fn wrapping_add(self, other: Self) -> Self {
+        u64::wrapping_add(self, other)
+    }
+    fn wrapping_sub(self, other: Self) -> Self {
+        u64::wrapping_sub(self, other)
+    }
+    fn from_round(i: usize) -> Self {
+        i as u64
+    }
+}
+
 /// Performs the Speck round function once.
 /// (S^{-\alpha}x + y) \oplus k, S^{\beta}y \oplus (S^{-\alpha}x + y) \oplus k
 ///
 /// Notice that (S^{-\alpha}x + y) \oplus k component gets used twice, thus
 /// we can simplify the round function to 2 rotations, 1 addition, and 2 XORs.
 #[inline(always)]
-fn round(x: &mut u64, y: &mut u64, k: &u64) {
-    *x = x.rotate_right(ALPHA).wrapping_add(*y) ^ k;
+fn round<W: Word>(x: &mut W, y: &mut W, k: &W) {
+    *x = x.rotate_right(ALPHA).wrapping_add(*y) ^ *k;
     *y = y.rotate_left(BETA) ^ *x;
 }
 
@@ -35,23 +101,46 @@ fn round(x: &mut u64, y: &mut u64, k: &u64) {
 /// Notice that that S^{-\beta}(x \oplus y) component gets used twice, thus
 /// we can simplify the round function to 2 rotations, 1 subtraction, and 2 XORs.
 #[inline(always)]
-fn inv_round(x: &mut u64, y: &mut u64, k: &u64) {
+fn inv_round<W: Word>(x: &mut W, y: &mut W, k: &W) {
     *y = (*y ^ *x).rotate_right(BETA);
-    *x = (*x ^ *k).wrapping_sub(*y).rotate_left(ALPHA);
+    *x = ((*x ^ *k).wrapping_sub(*y)).rotate_left(ALPHA);
 }
 
-/// Computes the Speck key schedule via the round function.
-#[inline(always)]
-fn key_schedule(k1: &mut u64, k2: &mut u64) -> [u64; ROUNDS] {
-    let mut schedule = [0u64; ROUNDS];
-    for i in 0..ROUNDS as u64 {
-        schedule[i as usize] = *k2;
-        round(k1, k2, &i)
+/// Computes a Speck key schedule for a key of `M` words, emitting `ROUNDS`
+/// round keys.
+///
+/// Given key words `K[0..M]` the schedule sets `k[0] = K[0]` and
+/// `l[0..M-1] = K[1..M]`, then iterates
+/// `l[i+M-1] = (k[i] + S^{-\alpha} l[i]) \oplus i` and
+/// `k[i+1] = S^{\beta} k[i] \oplus l[i+M-1]`, emitting each `k[i]`.
+/// For `M = 2` this reduces to running the round function over the two key
+/// words, matching the original Speck128/128 schedule.
+fn key_schedule<W: Word, const M: usize, const ROUNDS: usize>(key: [W; M]) -> [W; ROUNDS] {
+    let mut schedule = [W::ZERO; ROUNDS];
+
+    // `l` holds the rotating sequence of key words. Only `l[i]` for
+    // `i < ROUNDS - 1` is ever read, so a `ROUNDS`-sized buffer suffices even
+    // though the recurrence nominally writes a few entries past the end.
+    let mut l = [W::ZERO; ROUNDS];
+    l[..M - 1].copy_from_slice(&key[1..M]);
+    let mut k = key[0];
+
+    for i in 0..ROUNDS {
+        schedule[i] = k;
+        if i + 1 < ROUNDS {
+            let new_l = k.wrapping_add(l[i].rotate_right(ALPHA)) ^ W::from_round(i);
+            let idx = i + M - 1;
+            if idx < ROUNDS {
+                l[idx] = new_l;
+            }
+            k = k.rotate_left(BETA) ^ new_l;
+        }
     }
+
     schedule
 }
 
-/// Implements Speck encryption/decryption.
+/// Implements Speck128/128 encryption/decryption.
 /// This tuple-struct takes a key schedule as input.
 ///
 /// TODO: Build an API around generating the key schedule
@@ -59,10 +148,11 @@ pub struct Speck([u64; ROUNDS]);
 
 impl Speck {
     pub fn new(key: &u128) -> Self {
-        let mut k1 = (key >> 64) as u64;
-        let mut k2 = *key as u64;
+        // The low word is `K[0]`, the high word is `K[1]`.
+        let k0 = *key as u64;
+        let k1 = (key >> 64) as u64;
 
-        Speck(key_schedule(&mut k1, &mut k2))
+        Speck(key_schedule::<u64, 2, ROUNDS>([k0, k1]))
     }
 
     /// Performs a raw encryption using Speck.
@@ -100,8 +190,63 @@ impl Speck {
         // The chunks are mutated in place, so we just put them back together
         chunk_2 as u128 | (chunk_1 as u128) << 64
     }
+
+    /// Encrypts a slice of blocks in place, running the round function over a
+    /// lane of independent blocks per round key. Because each block is
+    /// independent the inner loop exposes plenty of instruction-level
+    /// parallelism, amortising the per-call overhead of the single-block path.
+    /// This is simply ECB over the slice, and is the bulk path the byte-buffer
+    /// cipher modes build on.
+    pub fn encrypt_blocks(&self, blocks: &mut [u128]) {
+        for lane in blocks.chunks_mut(BLOCK_LANES) {
+            let n = lane.len();
+            let mut xs = [0u64; BLOCK_LANES];
+            let mut ys = [0u64; BLOCK_LANES];
+            for j in 0..n {
+                xs[j] = (lane[j] >> 64) as u64;
+                ys[j] = lane[j] as u64;
+            }
+
+            for round_key in &self.0 {
+                for j in 0..n {
+                    round(&mut xs[j], &mut ys[j], round_key);
+                }
+            }
+
+            for j in 0..n {
+                lane[j] = ys[j] as u128 | (xs[j] as u128) << 64;
+            }
+        }
+    }
+
+    /// Decrypts a slice of blocks in place; the inverse of [`encrypt_blocks`].
+    pub fn decrypt_blocks(&self, blocks: &mut [u128]) {
+        for lane in blocks.chunks_mut(BLOCK_LANES) {
+            let n = lane.len();
+            let mut xs = [0u64; BLOCK_LANES];
+            let mut ys = [0u64; BLOCK_LANES];
+            for j in 0..n {
+                xs[j] = (lane[j] >> 64) as u64;
+                ys[j] = lane[j] as u64;
+            }
+
+            for round_key in self.0.iter().rev() {
+                for j in 0..n {
+                    inv_round(&mut xs[j], &mut ys[j], round_key);
+                }
+            }
+
+            for j in 0..n {
+                lane[j] = ys[j] as u128 | (xs[j] as u128) << 64;
+            }
+        }
+    }
 }
 
+// ECB is exempt from the batched-path wiring: its trait signature is inherently
+// one `u128` in / one `u128` out, with no buffer for the caller to hand over a
+// slice, so there is nothing to batch here. The batched `encrypt_blocks` path is
+// wired into the byte-buffer modes (CTR/XTS) instead, where a slice is available.
 impl ECB for Speck {
     fn encrypt(&self, plaintext: &u128) -> u128 {
         self.encrypt(plaintext)
@@ -112,6 +257,272 @@ impl ECB for Speck {
     }
 }
 
+/// Multiplies a 128-bit tweak by the primitive element α = 2 in GF(2^128),
+/// the field used by XTS. The tweak is treated as a little-endian value, so the
+/// multiply is a 1-bit left shift; if the top bit carries out we reduce modulo
+/// the field polynomial by XORing the lowest byte with 0x87.
+#[inline(always)]
+fn gf_mul_alpha(tweak: u128) -> u128 {
+    let carry = tweak >> 127;
+    (tweak << 1) ^ (carry * 0x87)
+}
+
+impl XTS for Speck {
+    fn encrypt(&self, tweak: &Self, sector_index: u128, data_unit: &mut [u8]) {
+        // XTS is undefined for data units shorter than a single block; the
+        // ciphertext-stealing path below also assumes at least one full block.
+        debug_assert!(data_unit.len() >= 16, "XTS data unit must be at least one block");
+        if data_unit.len() < 16 {
+            return;
+        }
+
+        // Derive the initial tweak from the little-endian sector index.
+        let mut t = tweak.encrypt(&sector_index);
+
+        let len = data_unit.len();
+        let rem = len % 16;
+        // Every block is enciphered in the simple loop except, when the unit is
+        // not block-aligned, the final full block which is deferred to the
+        // ciphertext-stealing step.
+        let simple = if rem == 0 { len / 16 } else { len / 16 - 1 };
+
+        let mut processed = 0;
+        while processed < simple {
+            let lane = core::cmp::min(BLOCK_LANES, simple - processed);
+            let mut blocks = [0u128; BLOCK_LANES];
+            let mut tweaks = [0u128; BLOCK_LANES];
+            for j in 0..lane {
+                let off = (processed + j) * 16;
+                let p = u128::from_le_bytes(chunk_to_block(&data_unit[off..off + 16]));
+                tweaks[j] = t;
+                blocks[j] = p ^ t;
+                t = gf_mul_alpha(t);
+            }
+            self.encrypt_blocks(&mut blocks[..lane]);
+            for j in 0..lane {
+                let off = (processed + j) * 16;
+                let c = blocks[j] ^ tweaks[j];
+                data_unit[off..off + 16].copy_from_slice(&c.to_le_bytes());
+            }
+            processed += lane;
+        }
+
+        if rem != 0 {
+            let off = simple * 16;
+            let tail = off + 16;
+            let t_next = gf_mul_alpha(t);
+
+            // Encipher the penultimate (full) block under the current tweak.
+            let p = u128::from_le_bytes(chunk_to_block(&data_unit[off..off + 16]));
+            let cc = (self.encrypt(&(p ^ t)) ^ t).to_le_bytes();
+
+            // Steal the tail of that ciphertext to complete the short block.
+            let mut pp = [0u8; 16];
+            pp[..rem].copy_from_slice(&data_unit[tail..tail + rem]);
+            pp[rem..].copy_from_slice(&cc[rem..]);
+
+            let pp = u128::from_le_bytes(pp);
+            let c = (self.encrypt(&(pp ^ t_next)) ^ t_next).to_le_bytes();
+            data_unit[off..off + 16].copy_from_slice(&c);
+            data_unit[tail..tail + rem].copy_from_slice(&cc[..rem]);
+        }
+    }
+
+    fn decrypt(&self, tweak: &Self, sector_index: u128, data_unit: &mut [u8]) {
+        debug_assert!(data_unit.len() >= 16, "XTS data unit must be at least one block");
+        if data_unit.len() < 16 {
+            return;
+        }
+
+        let mut t = tweak.encrypt(&sector_index);
+
+        let len = data_unit.len();
+        let rem = len % 16;
+        let simple = if rem == 0 { len / 16 } else { len / 16 - 1 };
+
+        let mut processed = 0;
+        while processed < simple {
+            let lane = core::cmp::min(BLOCK_LANES, simple - processed);
+            let mut blocks = [0u128; BLOCK_LANES];
+            let mut tweaks = [0u128; BLOCK_LANES];
+            for j in 0..lane {
+                let off = (processed + j) * 16;
+                let c = u128::from_le_bytes(chunk_to_block(&data_unit[off..off + 16]));
+                tweaks[j] = t;
+                blocks[j] = c ^ t;
+                t = gf_mul_alpha(t);
+            }
+            self.decrypt_blocks(&mut blocks[..lane]);
+            for j in 0..lane {
+                let off = (processed + j) * 16;
+                let p = blocks[j] ^ tweaks[j];
+                data_unit[off..off + 16].copy_from_slice(&p.to_le_bytes());
+            }
+            processed += lane;
+        }
+
+        if rem != 0 {
+            let off = simple * 16;
+            let tail = off + 16;
+            let t_next = gf_mul_alpha(t);
+
+            // The final full ciphertext block was encrypted under `t_next`.
+            let c = u128::from_le_bytes(chunk_to_block(&data_unit[off..off + 16]));
+            let pp = (self.decrypt(&(c ^ t_next)) ^ t_next).to_le_bytes();
+
+            // Reassemble the stolen ciphertext block and decrypt it under `t`.
+            let mut cc = [0u8; 16];
+            cc[..rem].copy_from_slice(&data_unit[tail..tail + rem]);
+            cc[rem..].copy_from_slice(&pp[rem..]);
+
+            let cc = u128::from_le_bytes(cc);
+            let p = (self.decrypt(&(cc ^ t)) ^ t).to_le_bytes();
+            data_unit[off..off + 16].copy_from_slice(&p);
+            data_unit[tail..tail + rem].copy_from_slice(&pp[..rem]);
+        }
+    }
+}
+
+impl CBC for Speck {
+    fn encrypt(&self, iv: u128, blocks: &mut [u128]) {
+        let mut chain = iv;
+        for block in blocks.iter_mut() {
+            chain = self.encrypt(&(*block ^ chain));
+            *block = chain;
+        }
+    }
+
+    fn decrypt(&self, iv: u128, blocks: &mut [u128]) {
+        let mut chain = iv;
+        for block in blocks.iter_mut() {
+            let ciphertext = *block;
+            *block = self.decrypt(&ciphertext) ^ chain;
+            chain = ciphertext;
+        }
+    }
+}
+
+impl CTR for Speck {
+    fn apply_keystream(&self, nonce: u128, data: &mut [u8]) {
+        // Encrypt the counter blocks a lane at a time so the batched path does
+        // the work, then XOR the keystream into the (byte) buffer.
+        let mut counter = nonce;
+        for super_chunk in data.chunks_mut(16 * BLOCK_LANES) {
+            let blocks = super_chunk.len().div_ceil(16);
+            let mut keystream = [0u128; BLOCK_LANES];
+            for block in keystream.iter_mut().take(blocks) {
+                *block = counter;
+                counter = counter.wrapping_add(1);
+            }
+            self.encrypt_blocks(&mut keystream[..blocks]);
+
+            for (chunk, ks) in super_chunk.chunks_mut(16).zip(keystream.iter()) {
+                let ks = ks.to_le_bytes();
+                for (byte, k) in chunk.iter_mut().zip(ks.iter()) {
+                    *byte ^= *k;
+                }
+            }
+        }
+    }
+}
+
+impl CFB for Speck {
+    fn encrypt(&self, iv: u128, data: &mut [u8]) {
+        let mut feedback = iv;
+        for chunk in data.chunks_mut(16) {
+            let keystream = self.encrypt(&feedback).to_le_bytes();
+            for (byte, ks) in chunk.iter_mut().zip(keystream.iter()) {
+                *byte ^= *ks;
+            }
+            // The full ciphertext block feeds the next round; a short trailing
+            // block is always last, so its (incomplete) feedback is unused.
+            feedback = u128::from_le_bytes(chunk_to_block(chunk));
+        }
+    }
+
+    fn decrypt(&self, iv: u128, data: &mut [u8]) {
+        let mut feedback = iv;
+        for chunk in data.chunks_mut(16) {
+            let keystream = self.encrypt(&feedback).to_le_bytes();
+            let next = u128::from_le_bytes(chunk_to_block(chunk));
+            for (byte, ks) in chunk.iter_mut().zip(keystream.iter()) {
+                *byte ^= *ks;
+            }
+            feedback = next;
+        }
+    }
+}
+
+/// Zero-extends a (possibly short, trailing) byte chunk into a 16-byte block so
+/// it can be read as a little-endian `u128` for the CFB feedback register.
+#[inline(always)]
+fn chunk_to_block(chunk: &[u8]) -> [u8; 16] {
+    let mut block = [0u8; 16];
+    block[..chunk.len()].copy_from_slice(chunk);
+    block
+}
+
+/// Generates a Speck variant type parameterised by word size, key length, and
+/// round count. Each variant stores its own round-key schedule and exposes
+/// public single-block `encrypt`/`decrypt` methods (unlike [`Speck`], whose
+/// single-block methods are crate-private), operating on the variant's native
+/// block type (`$block`).
+macro_rules! speck_variant {
+    ($(#[$meta:meta])* $name:ident, $word:ty, $block:ty, $bits:expr, $m:expr, $rounds:expr) => {
+        $(#[$meta])*
+        pub struct $name([$word; $rounds]);
+
+        impl $name {
+            /// Builds the key schedule from the `M` key words, ordered with
+            /// `K[0]` first (least significant).
+            pub fn new(key: [$word; $m]) -> Self {
+                $name(key_schedule::<$word, $m, $rounds>(key))
+            }
+
+            /// Encrypts a single native block.
+            pub fn encrypt(&self, plaintext: &$block) -> $block {
+                let mut chunk_1 = (plaintext >> $bits) as $word;
+                let mut chunk_2 = *plaintext as $word;
+
+                for round_key in &self.0 {
+                    round(&mut chunk_1, &mut chunk_2, round_key);
+                }
+
+                chunk_2 as $block | (chunk_1 as $block) << $bits
+            }
+
+            /// Decrypts a single native block.
+            pub fn decrypt(&self, ciphertext: &$block) -> $block {
+                let mut chunk_1 = (ciphertext >> $bits) as $word;
+                let mut chunk_2 = *ciphertext as $word;
+
+                for round_key in self.0.iter().rev() {
+                    inv_round(&mut chunk_1, &mut chunk_2, round_key);
+                }
+
+                chunk_2 as $block | (chunk_1 as $block) << $bits
+            }
+        }
+    };
+}
+
+speck_variant!(
+    /// Speck64/96: 32-bit words, a 96-bit key, and 26 rounds.
+    Speck64_96, u32, u64, 32, 3, 26
+);
+speck_variant!(
+    /// Speck64/128: 32-bit words, a 128-bit key, and 27 rounds.
+    Speck64_128, u32, u64, 32, 4, 27
+);
+speck_variant!(
+    /// Speck128/192: 64-bit words, a 192-bit key, and 33 rounds.
+    Speck128_192, u64, u128, 64, 3, 33
+);
+speck_variant!(
+    /// Speck128/256: 64-bit words, a 256-bit key, and 34 rounds.
+    Speck128_256, u64, u128, 64, 4, 34
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +549,143 @@ mod tests {
         assert_eq!(<Speck as ECB>::encrypt(&speck, &plaintext), ciphertext);
         assert_eq!(<Speck as ECB>::decrypt(&speck, &ciphertext), plaintext);
     }
+
+    #[test]
+    fn test_speck_xts_block_aligned_round_trip() {
+        let data_key = Speck::new(&0x0f0e0d0c0b0a09080706050403020100);
+        let tweak_key = Speck::new(&0x1f1e1d1c1b1a19181716151413121110);
+
+        let plaintext = *b"sector-aligned data unit, 32 by!";
+        let mut buf = plaintext;
+
+        <Speck as XTS>::encrypt(&data_key, &tweak_key, 0x42, &mut buf);
+        assert_ne!(buf, plaintext);
+        <Speck as XTS>::decrypt(&data_key, &tweak_key, 0x42, &mut buf);
+        assert_eq!(buf, plaintext);
+    }
+
+    #[test]
+    fn test_speck_xts_ciphertext_stealing_round_trip() {
+        let data_key = Speck::new(&0x0f0e0d0c0b0a09080706050403020100);
+        let tweak_key = Speck::new(&0x1f1e1d1c1b1a19181716151413121110);
+
+        // 20 bytes: one full block plus a 4-byte remainder exercises stealing.
+        let plaintext = *b"ciphertext stealing!";
+        let mut buf = plaintext;
+
+        <Speck as XTS>::encrypt(&data_key, &tweak_key, 7, &mut buf);
+        assert_ne!(buf, plaintext);
+        <Speck as XTS>::decrypt(&data_key, &tweak_key, 7, &mut buf);
+        assert_eq!(buf, plaintext);
+    }
+
+    #[test]
+    fn test_speck_batched_blocks_match_single_block() {
+        let speck = Speck::new(&0x0f0e0d0c0b0a09080706050403020100);
+        // More than one lane so the chunking is exercised.
+        let plaintext: [u128; 10] =
+            [0, 1, 2, 3, 0xdead_beef, 5, 6, 7, 0xfeed_face, 0xffff_ffff_ffff_ffff];
+
+        let mut buf = plaintext;
+        speck.encrypt_blocks(&mut buf);
+        for (block, expected) in buf.iter().zip(plaintext.iter()) {
+            assert_eq!(*block, speck.encrypt(expected));
+        }
+
+        speck.decrypt_blocks(&mut buf);
+        assert_eq!(buf, plaintext);
+    }
+
+    #[test]
+    fn test_speck_cbc_round_trip() {
+        let speck = Speck::new(&0x0f0e0d0c0b0a09080706050403020100);
+        let iv: u128 = 0x0102030405060708090a0b0c0d0e0f10;
+        let plaintext = [0x1111_1111_1111_1111_1111_1111_1111_1111u128, 0x2222, 0x3333];
+        let mut buf = plaintext;
+
+        <Speck as CBC>::encrypt(&speck, iv, &mut buf);
+        assert_ne!(buf, plaintext);
+        <Speck as CBC>::decrypt(&speck, iv, &mut buf);
+        assert_eq!(buf, plaintext);
+    }
+
+    #[test]
+    fn test_speck_ctr_round_trip() {
+        let speck = Speck::new(&0x0f0e0d0c0b0a09080706050403020100);
+        let nonce: u128 = 0xdeadbeef;
+        let plaintext = *b"CTR turns Speck into a stream cipher.";
+        let mut buf = plaintext;
+
+        speck.apply_keystream(nonce, &mut buf);
+        assert_ne!(buf, plaintext);
+        // Re-applying the same keystream recovers the plaintext.
+        speck.apply_keystream(nonce, &mut buf);
+        assert_eq!(buf, plaintext);
+    }
+
+    #[test]
+    fn test_speck_cfb_round_trip() {
+        let speck = Speck::new(&0x0f0e0d0c0b0a09080706050403020100);
+        let iv: u128 = 0x0102030405060708090a0b0c0d0e0f10;
+        let plaintext = *b"CFB is self-synchronising.";
+        let mut buf = plaintext;
+
+        <Speck as CFB>::encrypt(&speck, iv, &mut buf);
+        assert_ne!(buf, plaintext);
+        <Speck as CFB>::decrypt(&speck, iv, &mut buf);
+        assert_eq!(buf, plaintext);
+    }
+
+    #[test]
+    fn test_speck64_96_encryption_and_decryption() {
+        // Speck64/96 test vectors (see Appendix C in the paper)
+        let speck = Speck64_96::new([0x03020100, 0x0b0a0908, 0x13121110]);
+        let plaintext: u64 = 0x74614620_736e6165;
+        let ciphertext: u64 = 0x9f7952ec_4175946c;
+
+        assert_eq!(speck.encrypt(&plaintext), ciphertext);
+        assert_eq!(speck.decrypt(&ciphertext), plaintext);
+    }
+
+    #[test]
+    fn test_speck64_128_encryption_and_decryption() {
+        // Speck64/128 test vectors (see Appendix C in the paper)
+        let speck = Speck64_128::new([0x03020100, 0x0b0a0908, 0x13121110, 0x1b1a1918]);
+        let plaintext: u64 = 0x3b726574_7475432d;
+        let ciphertext: u64 = 0x8c6fa548_454e028b;
+
+        assert_eq!(speck.encrypt(&plaintext), ciphertext);
+        assert_eq!(speck.decrypt(&ciphertext), plaintext);
+    }
+
+    #[test]
+    fn test_speck128_192_encryption_and_decryption() {
+        // Speck128/192 test vectors (see Appendix C in the paper)
+        let speck = Speck128_192::new([
+            0x0706050403020100,
+            0x0f0e0d0c0b0a0908,
+            0x1716151413121110,
+        ]);
+        let plaintext: u128 = 0x7261482066656968_43206f7420746e65;
+        let ciphertext: u128 = 0x1be4cf3a13135566_f9bc185de03c1886;
+
+        assert_eq!(speck.encrypt(&plaintext), ciphertext);
+        assert_eq!(speck.decrypt(&ciphertext), plaintext);
+    }
+
+    #[test]
+    fn test_speck128_256_encryption_and_decryption() {
+        // Speck128/256 test vectors (see Appendix C in the paper)
+        let speck = Speck128_256::new([
+            0x0706050403020100,
+            0x0f0e0d0c0b0a0908,
+            0x1716151413121110,
+            0x1f1e1d1c1b1a1918,
+        ]);
+        let plaintext: u128 = 0x65736f6874206e49_202e72656e6f6f70;
+        let ciphertext: u128 = 0x4109010405c0f53e_4eeeb48d9c188f43;
+
+        assert_eq!(speck.encrypt(&plaintext), ciphertext);
+        assert_eq!(speck.decrypt(&ciphertext), plaintext);
+    }
 }
@@ -0,0 +1,442 @@
+//! A byte-oriented streaming layer over the raw block cipher.
+//!
+//! The [`Speck`](crate::Speck) type only speaks `u128` blocks, but the
+//! disk/file-encryption use cases operate on byte buffers of arbitrary length.
+//! This module provides `update`/`finalize` style [`Encryptor`]/[`Decryptor`]
+//! types that assemble blocks from bytes (little- or big-endian), buffer
+//! partial blocks across calls, and apply PKCS#7 padding on `finalize` for the
+//! block-aligned modes (ECB, CBC). The stream modes (CTR, CFB) need no padding.
+//!
+//! Everything here is `no_std`: callers supply the output slice and the methods
+//! return how many bytes were written into it.
+
+use crate::Speck;
+
+/// The byte order used to assemble a block from bytes and to serialise it back.
+#[derive(Clone, Copy)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    fn to_block(self, bytes: &[u8; 16]) -> u128 {
+        match self {
+            Endianness::Little => u128::from_le_bytes(*bytes),
+            Endianness::Big => u128::from_be_bytes(*bytes),
+        }
+    }
+
+    fn to_bytes(self, value: u128) -> [u8; 16] {
+        match self {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big => value.to_be_bytes(),
+        }
+    }
+}
+
+/// Encryption state for each supported mode. Block modes buffer plaintext until
+/// a whole block is available; stream modes keep a keystream block and a cursor
+/// into it.
+enum EncMode {
+    Ecb,
+    Cbc { chain: u128 },
+    Ctr { counter: u128, keystream: [u8; 16], pos: usize },
+    Cfb { feedback: u128, keystream: [u8; 16], produced: [u8; 16], pos: usize },
+}
+
+impl EncMode {
+    fn is_block(&self) -> bool {
+        matches!(self, EncMode::Ecb | EncMode::Cbc { .. })
+    }
+}
+
+/// A buffered, byte-oriented encryptor.
+pub struct Encryptor<'a> {
+    speck: &'a Speck,
+    endian: Endianness,
+    mode: EncMode,
+    buffer: [u8; 16],
+    buffered: usize,
+}
+
+impl<'a> Encryptor<'a> {
+    /// ECB with PKCS#7 padding. WARNING: ECB lacks diffusion; prefer CBC or a
+    /// stream mode for real data.
+    pub fn ecb(speck: &'a Speck, endian: Endianness) -> Self {
+        Self::block(speck, endian, EncMode::Ecb)
+    }
+
+    /// CBC with PKCS#7 padding, seeded by `iv`.
+    pub fn cbc(speck: &'a Speck, endian: Endianness, iv: u128) -> Self {
+        Self::block(speck, endian, EncMode::Cbc { chain: iv })
+    }
+
+    /// CTR keystream mode, seeded by `nonce`. Produces no padding.
+    pub fn ctr(speck: &'a Speck, endian: Endianness, nonce: u128) -> Self {
+        Self::block(speck, endian, EncMode::Ctr { counter: nonce, keystream: [0; 16], pos: 16 })
+    }
+
+    /// CFB stream mode, seeded by `iv`. Produces no padding.
+    pub fn cfb(speck: &'a Speck, endian: Endianness, iv: u128) -> Self {
+        Self::block(
+            speck,
+            endian,
+            EncMode::Cfb { feedback: iv, keystream: [0; 16], produced: [0; 16], pos: 16 },
+        )
+    }
+
+    fn block(speck: &'a Speck, endian: Endianness, mode: EncMode) -> Self {
+        Encryptor { speck, endian, mode, buffer: [0; 16], buffered: 0 }
+    }
+
+    /// Feeds `input` through the cipher, writing whatever whole blocks (or
+    /// stream bytes) become available into `output`. Returns the number of
+    /// bytes written.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        if self.mode.is_block() {
+            self.update_block(input, output)
+        } else {
+            self.update_stream(input, output)
+        }
+    }
+
+    /// Completes the stream: for block modes this appends a PKCS#7 pad block;
+    /// for stream modes there is nothing left to flush. Returns the number of
+    /// bytes written.
+    pub fn finalize(self, output: &mut [u8]) -> usize {
+        if !self.mode.is_block() {
+            return 0;
+        }
+
+        // PKCS#7: always append between 1 and 16 padding bytes.
+        let pad = 16 - self.buffered;
+        let mut block = self.buffer;
+        for byte in block.iter_mut().skip(self.buffered) {
+            *byte = pad as u8;
+        }
+        let mut this = self;
+        this.seal_block(&block, &mut output[..16]);
+        16
+    }
+
+    fn update_block(&mut self, mut input: &[u8], output: &mut [u8]) -> usize {
+        let mut written = 0;
+        while !input.is_empty() {
+            let take = core::cmp::min(16 - self.buffered, input.len());
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&input[..take]);
+            self.buffered += take;
+            input = &input[take..];
+
+            if self.buffered == 16 {
+                let block = self.buffer;
+                self.seal_block(&block, &mut output[written..written + 16]);
+                self.buffered = 0;
+                written += 16;
+            }
+        }
+        written
+    }
+
+    /// Encrypts one full plaintext block and writes the 16 ciphertext bytes.
+    fn seal_block(&mut self, block: &[u8; 16], output: &mut [u8]) {
+        let (speck, endian) = (self.speck, self.endian);
+        let plaintext = endian.to_block(block);
+        let ciphertext = match &mut self.mode {
+            EncMode::Ecb => speck.encrypt(&plaintext),
+            EncMode::Cbc { chain } => {
+                let c = speck.encrypt(&(plaintext ^ *chain));
+                *chain = c;
+                c
+            }
+            _ => unreachable!("seal_block is only used by block modes"),
+        };
+        output.copy_from_slice(&endian.to_bytes(ciphertext));
+    }
+
+    fn update_stream(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        for (i, &byte) in input.iter().enumerate() {
+            output[i] = self.stream_byte(byte);
+        }
+        input.len()
+    }
+
+    /// Feeds one plaintext byte through a stream mode, refilling the keystream
+    /// block and advancing the mode state as whole blocks complete.
+    fn stream_byte(&mut self, plaintext: u8) -> u8 {
+        let (speck, endian) = (self.speck, self.endian);
+        match &mut self.mode {
+            EncMode::Ctr { counter, keystream, pos } => {
+                if *pos == 16 {
+                    *keystream = endian.to_bytes(speck.encrypt(counter));
+                    *counter = counter.wrapping_add(1);
+                    *pos = 0;
+                }
+                let c = plaintext ^ keystream[*pos];
+                *pos += 1;
+                c
+            }
+            EncMode::Cfb { feedback, keystream, produced, pos } => {
+                if *pos == 16 {
+                    *keystream = endian.to_bytes(speck.encrypt(feedback));
+                    *pos = 0;
+                }
+                let c = plaintext ^ keystream[*pos];
+                produced[*pos] = c;
+                *pos += 1;
+                if *pos == 16 {
+                    // The completed ciphertext block feeds the next keystream.
+                    *feedback = endian.to_block(produced);
+                }
+                c
+            }
+            _ => unreachable!("stream_byte is only used by stream modes"),
+        }
+    }
+}
+
+/// Decryption state, mirroring [`EncMode`]. Stream modes are self-inverse up to
+/// the feedback source, so they reuse the same keystream machinery.
+enum DecMode {
+    Ecb,
+    Cbc { chain: u128 },
+    Ctr { counter: u128, keystream: [u8; 16], pos: usize },
+    Cfb { feedback: u128, keystream: [u8; 16], produced: [u8; 16], pos: usize },
+}
+
+impl DecMode {
+    fn is_block(&self) -> bool {
+        matches!(self, DecMode::Ecb | DecMode::Cbc { .. })
+    }
+}
+
+/// A buffered, byte-oriented decryptor.
+///
+/// Block modes keep the most recently decrypted block held back so that the
+/// final block's PKCS#7 padding can be stripped on [`finalize`](Self::finalize).
+pub struct Decryptor<'a> {
+    speck: &'a Speck,
+    endian: Endianness,
+    mode: DecMode,
+    buffer: [u8; 16],
+    buffered: usize,
+    held: [u8; 16],
+    has_held: bool,
+}
+
+impl<'a> Decryptor<'a> {
+    pub fn ecb(speck: &'a Speck, endian: Endianness) -> Self {
+        Self::block(speck, endian, DecMode::Ecb)
+    }
+
+    pub fn cbc(speck: &'a Speck, endian: Endianness, iv: u128) -> Self {
+        Self::block(speck, endian, DecMode::Cbc { chain: iv })
+    }
+
+    pub fn ctr(speck: &'a Speck, endian: Endianness, nonce: u128) -> Self {
+        Self::block(speck, endian, DecMode::Ctr { counter: nonce, keystream: [0; 16], pos: 16 })
+    }
+
+    pub fn cfb(speck: &'a Speck, endian: Endianness, iv: u128) -> Self {
+        Self::block(
+            speck,
+            endian,
+            DecMode::Cfb { feedback: iv, keystream: [0; 16], produced: [0; 16], pos: 16 },
+        )
+    }
+
+    fn block(speck: &'a Speck, endian: Endianness, mode: DecMode) -> Self {
+        Decryptor {
+            speck,
+            endian,
+            mode,
+            buffer: [0; 16],
+            buffered: 0,
+            held: [0; 16],
+            has_held: false,
+        }
+    }
+
+    /// Feeds `input` through the cipher, writing recovered plaintext into
+    /// `output`. For block modes the last decrypted block is withheld until
+    /// `finalize` so its padding can be removed. Returns the bytes written.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        if self.mode.is_block() {
+            self.update_block(input, output)
+        } else {
+            self.update_stream(input, output)
+        }
+    }
+
+    /// Completes the stream: for block modes this flushes the held block with
+    /// its PKCS#7 padding stripped. Returns the bytes written, or `0` when the
+    /// stream carried no block data or the padding is invalid (as happens on
+    /// corrupted or forged ciphertext).
+    pub fn finalize(self, output: &mut [u8]) -> usize {
+        if !self.mode.is_block() {
+            return 0;
+        }
+
+        // A block stream that never produced a full block has nothing to unpad.
+        if !self.has_held {
+            return 0;
+        }
+
+        // The held block is the last plaintext block and carries the padding.
+        let pad = self.held[15] as usize;
+        // PKCS#7 pads by 1..=16 bytes, each equal to the pad length.
+        if !(1..=16).contains(&pad) {
+            return 0;
+        }
+        if self.held[16 - pad..].iter().any(|&b| b as usize != pad) {
+            return 0;
+        }
+
+        let len = 16 - pad;
+        output[..len].copy_from_slice(&self.held[..len]);
+        len
+    }
+
+    fn update_block(&mut self, mut input: &[u8], output: &mut [u8]) -> usize {
+        let mut written = 0;
+        while !input.is_empty() {
+            let take = core::cmp::min(16 - self.buffered, input.len());
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&input[..take]);
+            self.buffered += take;
+            input = &input[take..];
+
+            if self.buffered == 16 {
+                let block = self.buffer;
+                let plain = self.open_block(&block);
+                // Emit the previously held block, then hold this one.
+                if self.has_held {
+                    output[written..written + 16].copy_from_slice(&self.held);
+                    written += 16;
+                }
+                self.held = plain;
+                self.has_held = true;
+                self.buffered = 0;
+            }
+        }
+        written
+    }
+
+    /// Decrypts one full ciphertext block into its plaintext bytes.
+    fn open_block(&mut self, block: &[u8; 16]) -> [u8; 16] {
+        let (speck, endian) = (self.speck, self.endian);
+        let ciphertext = endian.to_block(block);
+        let plaintext = match &mut self.mode {
+            DecMode::Ecb => speck.decrypt(&ciphertext),
+            DecMode::Cbc { chain } => {
+                let p = speck.decrypt(&ciphertext) ^ *chain;
+                *chain = ciphertext;
+                p
+            }
+            _ => unreachable!("open_block is only used by block modes"),
+        };
+        endian.to_bytes(plaintext)
+    }
+
+    fn update_stream(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        for (i, &byte) in input.iter().enumerate() {
+            output[i] = self.stream_byte(byte);
+        }
+        input.len()
+    }
+
+    fn stream_byte(&mut self, ciphertext: u8) -> u8 {
+        let (speck, endian) = (self.speck, self.endian);
+        match &mut self.mode {
+            DecMode::Ctr { counter, keystream, pos } => {
+                if *pos == 16 {
+                    *keystream = endian.to_bytes(speck.encrypt(counter));
+                    *counter = counter.wrapping_add(1);
+                    *pos = 0;
+                }
+                let p = ciphertext ^ keystream[*pos];
+                *pos += 1;
+                p
+            }
+            DecMode::Cfb { feedback, keystream, produced, pos } => {
+                if *pos == 16 {
+                    *keystream = endian.to_bytes(speck.encrypt(feedback));
+                    *pos = 0;
+                }
+                let p = ciphertext ^ keystream[*pos];
+                // The incoming ciphertext byte (not the plaintext) is fed back.
+                produced[*pos] = ciphertext;
+                *pos += 1;
+                if *pos == 16 {
+                    *feedback = endian.to_block(produced);
+                }
+                p
+            }
+            _ => unreachable!("stream_byte is only used by stream modes"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speck() -> Speck {
+        Speck::new(&0x0f0e0d0c0b0a09080706050403020100)
+    }
+
+    #[test]
+    fn test_cbc_stream_round_trip_with_padding() {
+        let speck = speck();
+        let message = b"streaming CBC over an arbitrary byte length";
+
+        let mut ciphertext = [0u8; 64];
+        let mut enc = Encryptor::cbc(&speck, Endianness::Little, 0x99);
+        let mut n = enc.update(&message[..10], &mut ciphertext);
+        n += enc.update(&message[10..], &mut ciphertext[n..]);
+        n += enc.finalize(&mut ciphertext[n..]);
+        assert_eq!(n % 16, 0);
+
+        let mut recovered = [0u8; 64];
+        let mut dec = Decryptor::cbc(&speck, Endianness::Little, 0x99);
+        let mut m = dec.update(&ciphertext[..n], &mut recovered);
+        m += dec.finalize(&mut recovered[m..]);
+        assert_eq!(&recovered[..m], message);
+    }
+
+    #[test]
+    fn test_ctr_stream_round_trip_unpadded() {
+        let speck = speck();
+        let message = b"CTR needs no padding at all";
+
+        let mut ciphertext = [0u8; 27];
+        let mut enc = Encryptor::ctr(&speck, Endianness::Little, 0x1234);
+        let n = enc.update(message, &mut ciphertext);
+        assert_eq!(n, message.len());
+        assert_eq!(enc.finalize(&mut []), 0);
+        assert_ne!(&ciphertext[..], &message[..]);
+
+        let mut recovered = [0u8; 27];
+        let mut dec = Decryptor::ctr(&speck, Endianness::Little, 0x1234);
+        dec.update(&ciphertext, &mut recovered);
+        assert_eq!(&recovered[..], &message[..]);
+    }
+
+    #[test]
+    fn test_cfb_stream_round_trip_split_updates() {
+        let speck = speck();
+        let message = b"CFB feeds ciphertext back across block boundaries";
+
+        let mut ciphertext = [0u8; 49];
+        let mut enc = Encryptor::cfb(&speck, Endianness::Little, 0xabcd);
+        // Feed in awkwardly sized chunks to exercise cross-call buffering.
+        let mut n = enc.update(&message[..7], &mut ciphertext);
+        n += enc.update(&message[7..20], &mut ciphertext[n..]);
+        n += enc.update(&message[20..], &mut ciphertext[n..]);
+        assert_eq!(n, message.len());
+
+        let mut recovered = [0u8; 49];
+        let mut dec = Decryptor::cfb(&speck, Endianness::Little, 0xabcd);
+        dec.update(&ciphertext, &mut recovered);
+        assert_eq!(&recovered[..], &message[..]);
+    }
+}